@@ -0,0 +1,158 @@
+use crate::conway::GradientStop;
+use serde::Deserialize;
+
+/// Presentation and key-binding settings, loaded from an optional
+/// `config.toml` in the current directory. Any field (or the file itself)
+/// that's missing keeps its default, so today's behavior is unchanged.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub fps: u64,
+    pub live_glyph: char,
+    pub dead_glyph: char,
+    pub gradient: Vec<GradientStop>,
+    pub keys: KeyBindings,
+}
+
+impl Config {
+    /// Loads `config.toml` from the current directory, falling back to
+    /// `Config::default()` if it's absent or fails to parse.
+    pub fn load() -> Self {
+        let config = match std::fs::read_to_string("config.toml") {
+            Ok(contents) => toml::from_str(&contents).unwrap_or_else(|e| {
+                eprintln!("Error parsing config.toml: {e}. Using defaults.");
+                Config::default()
+            }),
+            Err(_) => Config::default(),
+        };
+
+        config.validated()
+    }
+
+    /// Clamps fields that would otherwise misbehave if a config file set
+    /// them to an out-of-range value, e.g. an `fps = 0` that would
+    /// divide-by-zero in the main loop's frame sleep.
+    fn validated(mut self) -> Self {
+        if self.fps == 0 {
+            eprintln!("config.toml: fps must be at least 1; using 1.");
+            self.fps = 1;
+        }
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            fps: 25,
+            live_glyph: '\u{2588}',
+            dead_glyph: ' ',
+            gradient: Vec::new(),
+            keys: KeyBindings::default(),
+        }
+    }
+}
+
+/// Key bindings for `main`'s event loop. Each field holds a single
+/// case-insensitive character; modifier keys (Ctrl, Esc) stay fixed.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub restart: char,
+    pub save_to_file: char,
+    pub save_state: char,
+    pub load_state: char,
+    pub toggle_pause: char,
+    pub cycle_neighborhood: char,
+    pub quit: char,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            restart: 'r',
+            save_to_file: 's',
+            save_state: 's',
+            load_state: 'l',
+            toggle_pause: 'p',
+            cycle_neighborhood: 'n',
+            quit: 'q',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_config() {
+        let toml = r##"
+            fps = 60
+            live_glyph = "#"
+            dead_glyph = "."
+
+            [[gradient]]
+            age = 0
+            color = [0, 255, 0]
+
+            [[gradient]]
+            age = 20
+            color = [255, 0, 0]
+
+            [keys]
+            restart = "x"
+            quit = "z"
+        "##;
+
+        let config: Config = toml::from_str(toml).unwrap();
+
+        assert_eq!(config.fps, 60);
+        assert_eq!(config.live_glyph, '#');
+        assert_eq!(config.dead_glyph, '.');
+        assert_eq!(
+            config.gradient,
+            vec![
+                GradientStop { age: 0, color: (0, 255, 0) },
+                GradientStop { age: 20, color: (255, 0, 0) },
+            ]
+        );
+        assert_eq!(config.keys.restart, 'x');
+        assert_eq!(config.keys.quit, 'z');
+        // Fields left out of the [keys] table keep their defaults.
+        assert_eq!(config.keys.save_state, 's');
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let config: Config = toml::from_str("fps = 10").unwrap();
+
+        assert_eq!(config.fps, 10);
+        assert_eq!(config.live_glyph, Config::default().live_glyph);
+        assert_eq!(config.keys.quit, 'q');
+    }
+
+    #[test]
+    fn empty_document_matches_default() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.fps, Config::default().fps);
+        assert_eq!(config.gradient, Config::default().gradient);
+    }
+
+    #[test]
+    fn rejects_malformed_toml() {
+        assert!(toml::from_str::<Config>("fps = \"not a number\"").is_err());
+    }
+
+    #[test]
+    fn validated_clamps_zero_fps_to_one() {
+        let config: Config = toml::from_str("fps = 0").unwrap();
+        assert_eq!(config.validated().fps, 1);
+    }
+
+    #[test]
+    fn validated_leaves_nonzero_fps_untouched() {
+        let config: Config = toml::from_str("fps = 60").unwrap();
+        assert_eq!(config.validated().fps, 60);
+    }
+}
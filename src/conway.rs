@@ -3,84 +3,464 @@ use crossterm::{
     QueueableCommand,
     cursor::{Hide, MoveTo, RestorePosition, SavePosition, Show},
     execute,
-    style::Print,
+    style::{Color, Print, ResetColor, SetForegroundColor},
     terminal::{
         EnterAlternateScreen, LeaveAlternateScreen, SetSize, disable_raw_mode, enable_raw_mode,
     },
 };
-use rand::Rng;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::Deserialize;
 use std::{
+    collections::{HashMap, VecDeque, hash_map::DefaultHasher},
     error::Error,
     fmt,
+    hash::{Hash, Hasher},
     io::{self, Write},
 };
 
+/// How many past generations are kept for still-life/oscillator detection.
+/// Caps the longest oscillator period that can be detected.
+const CYCLE_HISTORY: usize = 32;
+
+const DEFAULT_LIVE_GLYPH: char = '\u{2588}';
+const DEFAULT_DEAD_GLYPH: char = ' ';
+
 pub struct Grid {
     saved: Vec<Vec<bool>>,
     grid: Vec<Vec<bool>>,
     rows: usize,
     cols: usize,
     paused: bool,
+    rule: Rule,
+    generation: u64,
+    /// Generation a configuration's hash was first seen at, for cycle detection.
+    seen_at: HashMap<u64, u64>,
+    /// Ring buffer of the hashes in `seen_at`, oldest first, so they can be evicted.
+    history: VecDeque<u64>,
+    /// Set once a still life/oscillator is detected; shown by `draw`.
+    status: Option<String>,
+    neighborhood: Neighborhood,
+    rng: StdRng,
+    /// How many consecutive generations each cell has been continuously alive.
+    age: Vec<Vec<u16>>,
+    live_glyph: char,
+    dead_glyph: char,
+    /// Age thresholds a live cell's color is picked from; empty means
+    /// monochrome (today's behavior).
+    gradient: Vec<GradientStop>,
+    /// Set by `prepare_terminal`; tells `Drop` whether there's actually a
+    /// raw-mode/alternate-screen session to tear down. Headless grids
+    /// (`from_pattern`, `from_file` without `prepare_terminal`) never set
+    /// this, so dropping them doesn't write terminal escape codes to stdout.
+    terminal_prepared: bool,
 }
 
-/// Creates a random two dimensional vector of booleans
-fn create_random_vec(rows: usize, cols: usize) -> Vec<Vec<bool>> {
-    let mut rng = rand::rng();
+/// One stop of an age-to-color gradient: cells at least `age` generations
+/// old (and younger than the next stop) are tinted `color`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct GradientStop {
+    pub age: u16,
+    pub color: (u8, u8, u8),
+}
+
+/// Strategy used by `count_neighbors` to decide which cells count as a
+/// cell's neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neighborhood {
+    /// The classic 3x3 Moore neighborhood, with hard (non-wrapping) edges.
+    #[default]
+    Moore,
+    /// A 3x3 Moore neighborhood that wraps around the grid's edges.
+    Toroidal,
+    /// For each of the 8 compass directions, step outward until the first
+    /// live cell (counted) or the grid's edge (not counted) is reached.
+    LineOfSight,
+}
+
+impl Neighborhood {
+    /// Cycles to the next mode, in the order it's declared.
+    fn next(self) -> Self {
+        match self {
+            Neighborhood::Moore => Neighborhood::Toroidal,
+            Neighborhood::Toroidal => Neighborhood::LineOfSight,
+            Neighborhood::LineOfSight => Neighborhood::Moore,
+        }
+    }
+}
+
+/// The 8 compass directions as `(row delta, col delta)` pairs.
+const DIRECTIONS: [(i64, i64); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A cellular automaton rule in B(irth)/S(urvival) notation, e.g. `B3/S23`
+/// for Conway's Game of Life or `B36/S23` for HighLife.
+///
+/// `birth[n]`/`survival[n]` say whether a dead/live cell with `n` neighbors
+/// is alive next generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    birth: [bool; 9],
+    survival: [bool; 9],
+}
+
+impl Rule {
+    /// Parses a `B<digits>/S<digits>` rule string.
+    ///
+    /// The `B`/`S` parts may appear in either order, are case-insensitive,
+    /// and either digit set may be empty (e.g. `B2/S` for Seeds).
+    pub fn parse(s: &str) -> Result<Self, GridError> {
+        let mut birth = [false; 9];
+        let mut survival = [false; 9];
+        let (mut seen_b, mut seen_s) = (false, false);
+
+        for part in s.trim().split('/') {
+            let mut chars = part.chars();
+            let tag = chars
+                .next()
+                .ok_or_else(|| GridError::BadRule(s.to_string()))?;
+            let digits = chars.as_str();
+
+            let table = match tag.to_ascii_uppercase() {
+                'B' if !seen_b => {
+                    seen_b = true;
+                    &mut birth
+                }
+                'S' if !seen_s => {
+                    seen_s = true;
+                    &mut survival
+                }
+                _ => return Err(GridError::BadRule(s.to_string())),
+            };
+
+            for d in digits.chars() {
+                let n = d.to_digit(10).ok_or_else(|| GridError::BadRule(s.to_string()))? as usize;
+                if n > 8 {
+                    return Err(GridError::BadRule(s.to_string()));
+                }
+                table[n] = true;
+            }
+        }
+
+        if !seen_b || !seen_s {
+            return Err(GridError::BadRule(s.to_string()));
+        }
+
+        Ok(Self { birth, survival })
+    }
+}
+
+impl Default for Rule {
+    /// Conway's Game of Life: `B3/S23`.
+    fn default() -> Self {
+        let mut birth = [false; 9];
+        let mut survival = [false; 9];
+        birth[3] = true;
+        survival[2] = true;
+        survival[3] = true;
+        Self { birth, survival }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = |table: &[bool; 9]| -> String {
+            (0..=8)
+                .filter(|&n| table[n])
+                .map(|n| n.to_string())
+                .collect()
+        };
+        write!(f, "B{}/S{}", digits(&self.birth), digits(&self.survival))
+    }
+}
+
+/// Parses the raw `0`/`1` rectangle format: one row per line, each line the
+/// same length.
+fn parse_plain(str: &str) -> Result<(Vec<Vec<bool>>, usize, usize), GridError> {
+    let mut grid = Vec::new();
+    let mut prev_len: Option<usize> = None;
+    for line in str.lines() {
+        let mut row = Vec::new();
+        if let Some(len) = prev_len {
+            if line.len() != len {
+                return Err(GridError::InconsistentWidth);
+            }
+        } else {
+            prev_len = Some(line.len())
+        }
+        for c in line.chars() {
+            match c {
+                '0' => row.push(false),
+                '1' => row.push(true),
+                invalid => {
+                    return Err(GridError::Parse(invalid));
+                }
+            }
+        }
+        grid.push(row);
+    }
+
+    let rows = str.lines().count();
+    let cols = str.lines().next().unwrap().len();
+
+    Ok((grid, rows, cols))
+}
+
+/// Whether `filename`/`contents` look like the RLE pattern format rather
+/// than the raw `0`/`1` rectangle: a `.rle` extension, or a leading `#`
+/// comment / `x = ...` header line.
+fn looks_like_rle(filename: &str, contents: &str) -> bool {
+    if filename.ends_with(".rle") {
+        return true;
+    }
+
+    contents
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| {
+            let line = line.trim().to_ascii_lowercase();
+            line.starts_with('#') || line.starts_with("x =") || line.starts_with("x=")
+        })
+}
+
+/// A parsed RLE pattern: its cells plus the header's declared dimensions
+/// and optional rule override. Bundled into a struct (rather than a tuple)
+/// to keep `parse_rle`'s signature simple for clippy's `type_complexity`.
+struct RlePattern {
+    grid: Vec<Vec<bool>>,
+    rows: usize,
+    cols: usize,
+    rule: Option<Rule>,
+}
+
+/// Parses the header line of an RLE file: `x = <cols>, y = <rows>[, rule = ...]`.
+fn parse_rle_header(line: &str) -> Result<(usize, usize, Option<Rule>), GridError> {
+    let mut cols = None;
+    let mut rows = None;
+    let mut rule = None;
+
+    for field in line.split(',') {
+        let field = field.trim();
+        if let Some((key, value)) = field.split_once('=') {
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "x" => {
+                    cols = Some(
+                        value
+                            .parse()
+                            .map_err(|_| GridError::Rle(format!("bad x value in '{line}'")))?,
+                    )
+                }
+                "y" => {
+                    rows = Some(
+                        value
+                            .parse()
+                            .map_err(|_| GridError::Rle(format!("bad y value in '{line}'")))?,
+                    )
+                }
+                "rule" => rule = Some(Rule::parse(value)?),
+                _ => {}
+            }
+        }
+    }
+
+    match (cols, rows) {
+        (Some(cols), Some(rows)) => Ok((cols, rows, rule)),
+        _ => Err(GridError::Rle(format!("missing x/y in header '{line}'"))),
+    }
+}
+
+/// Parses the RLE pattern format into a grid sized from its header, placing
+/// the pattern in the top-left corner and padding short rows as dead.
+fn parse_rle(str: &str) -> Result<RlePattern, GridError> {
+    let mut header = None;
+    let mut body = String::new();
+
+    for line in str.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if header.is_none() {
+            header = Some(parse_rle_header(line)?);
+            continue;
+        }
+        body.push_str(line);
+    }
+
+    let (cols, rows, rule) = header.ok_or(GridError::Rle("empty RLE file".into()))?;
+    let mut grid = vec![vec![false; cols]; rows];
+
+    let mut run = String::new();
+    let (mut x, mut y) = (0usize, 0usize);
 
+    for c in body.chars() {
+        match c {
+            '0'..='9' => run.push(c),
+            'b' | 'o' | '$' => {
+                let count = if run.is_empty() {
+                    1
+                } else {
+                    run.parse()
+                        .map_err(|_| GridError::Rle(format!("bad run count before '{c}'")))?
+                };
+                run.clear();
+
+                match c {
+                    'b' => x += count,
+                    'o' => {
+                        for _ in 0..count {
+                            if y < rows && x < cols {
+                                grid[y][x] = true;
+                            }
+                            x += 1;
+                        }
+                    }
+                    '$' => {
+                        y += count;
+                        x = 0;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            '!' => break,
+            other => return Err(GridError::Rle(format!("unexpected character '{other}'"))),
+        }
+    }
+
+    Ok(RlePattern { grid, rows, cols, rule })
+}
+
+/// Run-length encodes a single row, trimming the trailing dead run (the
+/// header's width already implies it).
+fn encode_rle_row(row: &[bool]) -> String {
+    let Some(last_live) = row.iter().rposition(|&cell| cell) else {
+        return String::new();
+    };
+
+    let mut out = String::new();
+    let mut run_cell = row[0];
+    let mut run_len = 0usize;
+    for &cell in &row[..=last_live] {
+        if cell == run_cell {
+            run_len += 1;
+        } else {
+            push_rle_run(&mut out, run_len, if run_cell { 'o' } else { 'b' });
+            run_cell = cell;
+            run_len = 1;
+        }
+    }
+    push_rle_run(&mut out, run_len, if run_cell { 'o' } else { 'b' });
+    out
+}
+
+/// Run-length encodes a whole grid's body (everything between the header
+/// line and the final `!`), collapsing blank rows into a `$` count.
+fn encode_rle_body(grid: &[Vec<bool>]) -> String {
+    let Some(last_live_row) = grid.iter().rposition(|row| row.iter().any(|&cell| cell)) else {
+        return "!".to_string();
+    };
+
+    let mut out = String::new();
+    let mut pending_row_ends = 0usize;
+
+    for (i, row) in grid.iter().enumerate().take(last_live_row + 1) {
+        if i > 0 {
+            pending_row_ends += 1;
+        }
+
+        let content = encode_rle_row(row);
+        if content.is_empty() {
+            continue;
+        }
+
+        if pending_row_ends > 0 {
+            push_rle_run(&mut out, pending_row_ends, '$');
+            pending_row_ends = 0;
+        }
+        out.push_str(&content);
+    }
+
+    out.push('!');
+    out
+}
+
+fn push_rle_run(out: &mut String, len: usize, tag: char) {
+    if len > 1 {
+        out.push_str(&len.to_string());
+    }
+    out.push(tag);
+}
+
+/// Hashes a cell configuration for still-life/oscillator detection.
+fn hash_cells(grid: &[Vec<bool>]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    grid.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Creates a random two dimensional vector of booleans
+fn create_random_vec(rows: usize, cols: usize, rng: &mut impl Rng) -> Vec<Vec<bool>> {
     (0..rows)
         .map(|_| (0..cols).map(|_| rng.random_bool(0.5)).collect())
         .collect()
 }
 
 impl Grid {
-    pub fn new(rows: usize, cols: usize) -> Self {
-        let grid = create_random_vec(rows, cols);
+    pub fn new(rows: usize, cols: usize, rule: Rule) -> Self {
+        let mut rng = StdRng::seed_from_u64(rand::rng().random());
+        let grid = create_random_vec(rows, cols, &mut rng);
         Self {
             saved: grid.clone(),
             grid,
             rows,
             cols,
             paused: false,
+            rule,
+            generation: 0,
+            seen_at: HashMap::new(),
+            history: VecDeque::new(),
+            status: None,
+            neighborhood: Neighborhood::default(),
+            rng,
+            age: vec![vec![0; cols]; rows],
+            live_glyph: DEFAULT_LIVE_GLYPH,
+            dead_glyph: DEFAULT_DEAD_GLYPH,
+            gradient: Vec::new(),
+            terminal_prepared: false,
         }
     }
 
     /// Creates a grid from file data
     ///
-    /// File must contain only 0s and 1s.
-    /// Each line must have the same length
+    /// Supports two formats, picked automatically:
+    /// - a raw rectangle of `0`/`1` characters, one row per line
+    /// - the run-length encoded "RLE" Life pattern format (`.rle` extension, or a
+    ///   leading `#` comment / `x = ...` header line)
+    ///
+    /// `rule_override` takes precedence over a `rule =` field found in an
+    /// RLE header; if neither is present the rule defaults to `B3/S23`.
     ///
-    /// Changes terminal size to match the grid size
-    pub fn from_file(filename: &str) -> Result<Self, GridError> {
+    /// This performs no terminal I/O; call `resize_terminal` afterwards if
+    /// the terminal should be resized to fit the loaded grid.
+    pub fn from_file(filename: &str, rule_override: Option<Rule>) -> Result<Self, GridError> {
         let str = std::fs::read_to_string(filename)?;
 
-        let mut grid = Vec::new();
-        let mut prev_len: Option<usize> = None;
-        for line in str.lines() {
-            let mut row = Vec::new();
-            if let Some(len) = prev_len {
-                if line.len() != len {
-                    return Err(GridError::InconsistentWidth);
-                }
-            } else {
-                prev_len = Some(line.len())
-            }
-            for c in line.chars() {
-                match c {
-                    '0' => row.push(false),
-                    '1' => row.push(true),
-                    invalid => {
-                        return Err(GridError::Parse(invalid));
-                    }
-                }
-            }
-            grid.push(row);
-        }
-
-        let rows = str.lines().count();
-        let cols = str.lines().next().unwrap().len();
-
-        // Resize terminal to fit the grid
-        execute!(io::stdout(), SetSize(cols as u16, rows as u16))?;
+        let (grid, rows, cols, rule) = if looks_like_rle(filename, &str) {
+            let RlePattern { grid, rows, cols, rule } = parse_rle(&str)?;
+            (grid, rows, cols, rule)
+        } else {
+            let (grid, rows, cols) = parse_plain(&str)?;
+            (grid, rows, cols, None)
+        };
+        let rule = rule_override.or(rule).unwrap_or_default();
 
         let paused = false;
         Ok(Self {
@@ -89,12 +469,75 @@ impl Grid {
             rows,
             cols,
             paused,
+            rule,
+            generation: 0,
+            seen_at: HashMap::new(),
+            history: VecDeque::new(),
+            status: None,
+            neighborhood: Neighborhood::default(),
+            rng: StdRng::seed_from_u64(rand::rng().random()),
+            age: vec![vec![0; cols]; rows],
+            live_glyph: DEFAULT_LIVE_GLYPH,
+            dead_glyph: DEFAULT_DEAD_GLYPH,
+            gradient: Vec::new(),
+            terminal_prepared: false,
         })
     }
 
+    /// Builds a grid directly from a known pattern, with no file or terminal
+    /// I/O. `seed` drives the RNG used for `restart`, making the whole grid
+    /// (and its subsequent generations) fully deterministic — useful for
+    /// headless/record-replay testing.
+    pub fn from_pattern(cells: Vec<Vec<bool>>, rule: Rule, seed: u64) -> Self {
+        let rows = cells.len();
+        let cols = cells.first().map_or(0, |row| row.len());
+        Self {
+            saved: cells.clone(),
+            grid: cells,
+            rows,
+            cols,
+            paused: false,
+            rule,
+            generation: 0,
+            seen_at: HashMap::new(),
+            history: VecDeque::new(),
+            status: None,
+            neighborhood: Neighborhood::default(),
+            rng: StdRng::seed_from_u64(seed),
+            age: vec![vec![0; cols]; rows],
+            live_glyph: DEFAULT_LIVE_GLYPH,
+            dead_glyph: DEFAULT_DEAD_GLYPH,
+            gradient: Vec::new(),
+            terminal_prepared: false,
+        }
+    }
+
+    /// Overrides the glyphs and age-to-color gradient used by `draw`,
+    /// e.g. with values loaded from `config.toml`.
+    pub fn set_display(&mut self, live_glyph: char, dead_glyph: char, gradient: Vec<GradientStop>) {
+        self.live_glyph = live_glyph;
+        self.dead_glyph = dead_glyph;
+        self.gradient = gradient;
+    }
+
+    /// Resizes the terminal to match the grid's dimensions.
+    pub fn resize_terminal(&self) -> ah::Result<()> {
+        execute!(io::stdout(), SetSize(self.cols as u16, self.rows as u16))?;
+        Ok(())
+    }
+
+    /// Steps the simulation forward `generations` times, with no I/O.
+    pub fn step_n(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.update_grid();
+        }
+    }
+
     /// Randomizes the grid
     pub fn restart(&mut self) {
-        self.grid = create_random_vec(self.rows, self.cols);
+        self.grid = create_random_vec(self.rows, self.cols, &mut self.rng);
+        self.age = vec![vec![0; self.cols]; self.rows];
+        self.clear_cycle_history();
     }
 
     /// Internally saves current grid state
@@ -105,22 +548,76 @@ impl Grid {
     /// Loads saved grid state
     pub fn load_state(&mut self) {
         self.grid = self.saved.clone();
+        self.age = vec![vec![0; self.cols]; self.rows];
+        self.clear_cycle_history();
+    }
+
+    /// Resets still-life/oscillator detection, e.g. after the grid is
+    /// replaced wholesale by `restart`/`load_state`.
+    fn clear_cycle_history(&mut self) {
+        self.generation = 0;
+        self.seen_at.clear();
+        self.history.clear();
+        self.status = None;
     }
 
     /// Saves current grid state to a file
+    ///
+    /// Writes the RLE pattern format when `filename` ends in `.rle`,
+    /// otherwise falls back to the raw `0`/`1` rectangle.
     pub fn save_to_file(&self, filename: &str) -> ah::Result<()> {
-        let mut file = std::fs::File::create(filename)?;
+        if filename.ends_with(".rle") {
+            self.save_to_file_rle(filename)
+        } else {
+            self.save_to_file_plain(filename)
+        }
+    }
+
+    /// Renders the current generation as the raw `0`/`1` rectangle format.
+    /// Pure and I/O-free, so it doubles as the serializer a record/replay
+    /// test harness diffs against checked-in reference files.
+    pub fn serialize_plain(&self) -> String {
+        let mut out = String::new();
         for row in &self.grid {
             for &cell in row {
-                let c = if cell { '1' } else { '0' };
-                write!(file, "{}", c)?;
+                out.push(if cell { '1' } else { '0' });
             }
-            writeln!(file)?;
+            out.push('\n');
         }
+        out
+    }
+
+    /// Renders the current generation as an RLE pattern file. Pure and
+    /// I/O-free, for the same reasons as `serialize_plain`.
+    pub fn serialize_rle(&self) -> String {
+        let mut out = format!("x = {}, y = {}, rule = {}\n", self.cols, self.rows, self.rule);
+        let body = encode_rle_body(&self.grid);
+        for chunk in body.as_bytes().chunks(70) {
+            out.push_str(std::str::from_utf8(chunk).expect("RLE body is ASCII"));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn save_to_file_plain(&self, filename: &str) -> ah::Result<()> {
+        std::fs::write(filename, self.serialize_plain())?;
+        Ok(())
+    }
+
+    fn save_to_file_rle(&self, filename: &str) -> ah::Result<()> {
+        std::fs::write(filename, self.serialize_rle())?;
         Ok(())
     }
 
     fn count_neighbors(&self, x: usize, y: usize) -> usize {
+        match self.neighborhood {
+            Neighborhood::Moore => self.count_neighbors_moore(x, y),
+            Neighborhood::Toroidal => self.count_neighbors_toroidal(x, y),
+            Neighborhood::LineOfSight => self.count_neighbors_line_of_sight(x, y),
+        }
+    }
+
+    fn count_neighbors_moore(&self, x: usize, y: usize) -> usize {
         let mut count = 0;
 
         // 3x3 grid centered in x, y while avoiding out-of-bounds
@@ -135,22 +632,111 @@ impl Grid {
         count
     }
 
-    /// Updates the grid according to the rules of Conway's Game of Life
+    fn count_neighbors_toroidal(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+
+        for &(di, dj) in &DIRECTIONS {
+            let i = (x as i64 + di).rem_euclid(self.rows as i64) as usize;
+            let j = (y as i64 + dj).rem_euclid(self.cols as i64) as usize;
+            if self.grid[i][j] {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn count_neighbors_line_of_sight(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+
+        for &(di, dj) in &DIRECTIONS {
+            let (mut i, mut j) = (x as i64, y as i64);
+            loop {
+                i += di;
+                j += dj;
+                if i < 0 || j < 0 || i >= self.rows as i64 || j >= self.cols as i64 {
+                    break;
+                }
+                if self.grid[i as usize][j as usize] {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Cycles to the next neighborhood mode (Moore -> toroidal -> line of sight)
+    pub fn cycle_neighborhood(&mut self) {
+        self.neighborhood = self.neighborhood.next();
+    }
+
+    /// Updates the grid according to `self.rule`
     pub fn update_grid(&mut self) {
         let mut new = vec![vec![false; self.cols]; self.rows];
+        let mut new_age = vec![vec![0u16; self.cols]; self.rows];
 
         for (i, row) in self.grid.iter().enumerate() {
             for (j, &cell) in row.iter().enumerate() {
                 let neighbors = self.count_neighbors(i, j);
 
-                new[i][j] = matches!((cell, neighbors), (true, 2..=3) | (false, 3));
+                let alive = if cell {
+                    self.rule.survival[neighbors]
+                } else {
+                    self.rule.birth[neighbors]
+                };
+
+                new[i][j] = alive;
+                new_age[i][j] = if alive { self.age[i][j].saturating_add(1) } else { 0 };
             }
         }
 
         self.grid = new;
+        self.age = new_age;
+        self.generation += 1;
+        self.detect_cycle();
     }
 
-    /// Prints the grid to the terminal
+    /// Hashes the current configuration against recent generations; on a
+    /// repeat, records a status message and auto-pauses.
+    fn detect_cycle(&mut self) {
+        let hash = hash_cells(&self.grid);
+
+        if let Some(&seen_gen) = self.seen_at.get(&hash) {
+            let period = self.generation - seen_gen;
+            self.status = Some(if period == 1 {
+                "stabilized: still life".to_string()
+            } else {
+                format!("stabilized: oscillator period {period}")
+            });
+            self.paused = true;
+            return;
+        }
+
+        self.seen_at.insert(hash, self.generation);
+        self.history.push_back(hash);
+        if self.history.len() > CYCLE_HISTORY {
+            if let Some(oldest) = self.history.pop_front() {
+                self.seen_at.remove(&oldest);
+            }
+        }
+    }
+
+    /// Picks the live-cell color for a given age from `self.gradient`: the
+    /// highest stop at or below `age`, falling back to the lowest stop for
+    /// ages younger than all of them. `None` (an empty gradient) means
+    /// monochrome.
+    fn color_for_age(&self, age: u16) -> Option<(u8, u8, u8)> {
+        self.gradient
+            .iter()
+            .filter(|stop| stop.age <= age)
+            .max_by_key(|stop| stop.age)
+            .or_else(|| self.gradient.iter().min_by_key(|stop| stop.age))
+            .map(|stop| stop.color)
+    }
+
+    /// Prints the grid to the terminal, tinting live cells by `self.gradient`
     pub fn draw(&mut self) {
         let mut stdout = io::stdout();
 
@@ -158,12 +744,25 @@ impl Grid {
 
         for (i, row) in self.grid.iter().enumerate() {
             stdout.queue(MoveTo(0, i as u16)).unwrap();
-            for &cell in row {
-                let c = if cell { '\u{2588}' } else { ' ' };
-                stdout.queue(Print(c)).unwrap();
+            for (j, &cell) in row.iter().enumerate() {
+                if !cell {
+                    stdout.queue(Print(self.dead_glyph)).unwrap();
+                    continue;
+                }
+
+                if let Some((r, g, b)) = self.color_for_age(self.age[i][j]) {
+                    stdout.queue(SetForegroundColor(Color::Rgb { r, g, b })).unwrap();
+                    stdout.queue(Print(self.live_glyph)).unwrap();
+                    stdout.queue(ResetColor).unwrap();
+                } else {
+                    stdout.queue(Print(self.live_glyph)).unwrap();
+                }
             }
+        }
 
-            if i < self.rows - 1 {}
+        if let Some(status) = &self.status {
+            stdout.queue(MoveTo(0, 0)).unwrap();
+            stdout.queue(Print(status)).unwrap();
         }
 
         stdout.queue(RestorePosition).unwrap();
@@ -173,9 +772,10 @@ impl Grid {
     /// Change terminal to raw mode and enter alternate screen
     ///
     /// Optional if button capture is not desired, and alternate screen is not needed
-    pub fn prepare_terminal(&self) -> ah::Result<()> {
+    pub fn prepare_terminal(&mut self) -> ah::Result<()> {
         enable_raw_mode()?;
         execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        self.terminal_prepared = true;
         Ok(())
     }
 
@@ -196,7 +796,12 @@ impl Grid {
 }
 
 impl Drop for Grid {
+    /// Only tears down the terminal if `prepare_terminal` actually set it up;
+    /// headless grids (e.g. `from_pattern`) drop without touching stdout.
     fn drop(&mut self) {
+        if !self.terminal_prepared {
+            return;
+        }
         if let Err(e) = self.restore_terminal() {
             eprintln!("Error restoring terminal: {}", e)
         }
@@ -208,6 +813,8 @@ pub enum GridError {
     Io(io::Error),
     Parse(char),
     InconsistentWidth,
+    Rle(String),
+    BadRule(String),
     // SaveWithoutLoad,
 }
 
@@ -223,6 +830,8 @@ impl fmt::Display for GridError {
             GridError::Io(e) => write!(f, "I/O error: {}", e),
             GridError::Parse(c) => write!(f, "Invalid character: '{}' (expected 0/1)", c),
             GridError::InconsistentWidth => write!(f, "Inconsistent row widths in file"),
+            GridError::Rle(msg) => write!(f, "Invalid RLE pattern: {}", msg),
+            GridError::BadRule(s) => write!(f, "Invalid rule string: '{}' (expected B<digits>/S<digits>)", s),
             // GridError::SaveWithoutLoad => {
             //     write!(f, "Cannot save grid that wasn't properly loaded")
             // }
@@ -231,3 +840,202 @@ impl fmt::Display for GridError {
 }
 
 impl Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The classic glider, as used in the RLE spec's own examples.
+    const GLIDER_RLE: &str = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+
+    #[test]
+    fn parse_rle_decodes_header_and_runs() {
+        let pattern = parse_rle(GLIDER_RLE).unwrap();
+        assert_eq!((pattern.rows, pattern.cols), (3, 3));
+        assert_eq!(pattern.rule, Some(Rule::parse("B3/S23").unwrap()));
+        assert_eq!(
+            pattern.grid,
+            vec![
+                vec![false, true, false],
+                vec![false, false, true],
+                vec![true, true, true],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rle_pads_short_rows_as_dead() {
+        // Header claims a 5-wide grid, but the body's rows are all
+        // narrower; the rest of each row should come back dead.
+        let pattern = parse_rle("x = 5, y = 2, rule = B3/S23\nbo$o!").unwrap();
+        assert_eq!(
+            pattern.grid,
+            vec![
+                vec![false, true, false, false, false],
+                vec![true, false, false, false, false],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rle_rejects_missing_header() {
+        assert!(matches!(parse_rle("bo$2bo$3o!"), Err(GridError::Rle(_))));
+    }
+
+    #[test]
+    fn parse_rle_rejects_unexpected_character() {
+        assert!(matches!(
+            parse_rle("x = 3, y = 3\nxbo$2bo$3o!"),
+            Err(GridError::Rle(_))
+        ));
+    }
+
+    #[test]
+    fn encode_rle_body_round_trips_through_parse_rle() {
+        let grid = vec![
+            vec![false, true, false],
+            vec![false, false, true],
+            vec![true, true, true],
+        ];
+
+        let encoded = encode_rle_body(&grid);
+        let header = format!("x = {}, y = {}, rule = B3/S23\n", grid[0].len(), grid.len());
+        let pattern = parse_rle(&format!("{header}{encoded}")).unwrap();
+
+        assert_eq!(pattern.grid, grid);
+    }
+
+    #[test]
+    fn encode_rle_body_collapses_blank_rows() {
+        // A single live cell on row 2 of an otherwise-empty 3-row grid
+        // should collapse the leading blank rows into one `$` run.
+        let grid = vec![vec![false, false], vec![false, false], vec![true, false]];
+        assert_eq!(encode_rle_body(&grid), "2$o!");
+    }
+
+    #[test]
+    fn rule_parse_accepts_default_life_rule() {
+        assert_eq!(Rule::parse("B3/S23").unwrap(), Rule::default());
+    }
+
+    #[test]
+    fn rule_parse_accepts_reversed_order_and_mixed_case() {
+        assert_eq!(Rule::parse("s23/b3").unwrap(), Rule::parse("B3/S23").unwrap());
+    }
+
+    #[test]
+    fn rule_parse_accepts_empty_digit_sets() {
+        // Seeds: births on 2 neighbors, no survivals at all.
+        let rule = Rule::parse("B2/S").unwrap();
+        assert_eq!(rule.to_string(), "B2/S");
+    }
+
+    #[test]
+    fn rule_parse_round_trips_through_display() {
+        let rule = Rule::parse("B36/S23").unwrap();
+        assert_eq!(Rule::parse(&rule.to_string()).unwrap(), rule);
+    }
+
+    #[test]
+    fn rule_parse_rejects_missing_b_or_s() {
+        assert!(matches!(Rule::parse("B3"), Err(GridError::BadRule(_))));
+        assert!(matches!(Rule::parse("B3/B3"), Err(GridError::BadRule(_))));
+    }
+
+    #[test]
+    fn rule_parse_rejects_out_of_range_digit() {
+        assert!(matches!(Rule::parse("B9/S23"), Err(GridError::BadRule(_))));
+    }
+
+    #[test]
+    fn rule_parse_rejects_unknown_tag() {
+        assert!(matches!(Rule::parse("B3/X23"), Err(GridError::BadRule(_))));
+    }
+
+    fn block_cells() -> Vec<Vec<bool>> {
+        let mut cells = vec![vec![false; 4]; 4];
+        for (r, c) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+            cells[r][c] = true;
+        }
+        cells
+    }
+
+    fn blinker_cells() -> Vec<Vec<bool>> {
+        let mut cells = vec![vec![false; 5]; 5];
+        for cell in cells[2][1..=3].iter_mut() {
+            *cell = true;
+        }
+        cells
+    }
+
+    #[test]
+    fn detect_cycle_flags_still_life() {
+        let mut grid = Grid::from_pattern(block_cells(), Rule::default(), 1);
+        grid.update_grid();
+        grid.update_grid();
+        assert_eq!(grid.status.as_deref(), Some("stabilized: still life"));
+        assert!(grid.paused);
+    }
+
+    #[test]
+    fn detect_cycle_flags_oscillator_period() {
+        let mut grid = Grid::from_pattern(blinker_cells(), Rule::default(), 1);
+        grid.step_n(3);
+        assert_eq!(grid.status.as_deref(), Some("stabilized: oscillator period 2"));
+        assert!(grid.paused);
+    }
+
+    #[test]
+    fn restart_clears_cycle_history() {
+        let mut grid = Grid::from_pattern(block_cells(), Rule::default(), 1);
+        grid.step_n(2);
+        assert!(grid.status.is_some());
+
+        grid.restart();
+
+        assert!(grid.status.is_none());
+        assert_eq!(grid.generation, 0);
+        assert!(grid.seen_at.is_empty());
+    }
+
+    fn single_cell_grid(rows: usize, cols: usize, r: usize, c: usize) -> Grid {
+        let mut cells = vec![vec![false; cols]; rows];
+        cells[r][c] = true;
+        Grid::from_pattern(cells, Rule::default(), 1)
+    }
+
+    #[test]
+    fn count_neighbors_moore_ignores_wraparound() {
+        let grid = single_cell_grid(3, 3, 2, 2);
+        assert_eq!(grid.count_neighbors_moore(0, 0), 0);
+    }
+
+    #[test]
+    fn count_neighbors_toroidal_wraps_around_edges() {
+        let grid = single_cell_grid(3, 3, 2, 2);
+        assert_eq!(grid.count_neighbors_toroidal(0, 0), 1);
+    }
+
+    #[test]
+    fn count_neighbors_line_of_sight_sees_past_immediate_neighbors() {
+        let mut cells = vec![vec![false; 5]];
+        cells[0][0] = true;
+        cells[0][3] = true;
+        let grid = Grid::from_pattern(cells, Rule::default(), 1);
+
+        assert_eq!(grid.count_neighbors_line_of_sight(0, 0), 1);
+        assert_eq!(grid.count_neighbors_moore(0, 0), 0);
+    }
+
+    #[test]
+    fn cycle_neighborhood_wraps_through_all_modes() {
+        let mut grid = single_cell_grid(3, 3, 0, 0);
+        assert_eq!(grid.neighborhood, Neighborhood::Moore);
+        grid.cycle_neighborhood();
+        assert_eq!(grid.neighborhood, Neighborhood::Toroidal);
+        grid.cycle_neighborhood();
+        assert_eq!(grid.neighborhood, Neighborhood::LineOfSight);
+        grid.cycle_neighborhood();
+        assert_eq!(grid.neighborhood, Neighborhood::Moore);
+    }
+}
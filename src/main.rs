@@ -1,41 +1,40 @@
-mod conway;
-
 use anyhow as ah;
-use conway::Grid;
+use conways_terminal_of_life::config::{Config, KeyBindings};
+use conways_terminal_of_life::conway::{Grid, Rule};
 use crossterm::{
     event::{self, Event, KeyCode, KeyModifiers as KM},
     terminal,
 };
 use std::{thread, time::Duration};
 
-/// FPS limit
-const FPS: u64 = 25;
-
 fn main() -> ah::Result<()> {
+    let config = Config::load();
+
     let (y, x) = terminal::size().expect("Unable to get size");
 
-    // provided is a bool showing if filename was given in the command line
-    let (filename, provided) = match std::env::args().nth(1) {
-        Some(filename) => (filename, true),
-        None => (String::from("grid.data"), false),
-    };
+    let (filename, provided, rule_override) = parse_args(std::env::args().skip(1))?;
 
     // if provided, get from_file, else create a new grid
-    let mut grid = Grid::from_file(&filename)
+    let mut grid = Grid::from_file(&filename, rule_override)
         .map_err(|e| {
             eprintln!("Error while loading from file: {e}. Creating default grid.");
             thread::sleep(Duration::from_secs(3));
         })
         .ok()
         .filter(|_| provided) // Returns None if provided is false
-        .unwrap_or(Grid::new(x as usize, y as usize)); // Creates new if value is none
+        .unwrap_or(Grid::new(x as usize, y as usize, rule_override.unwrap_or_default())); // Creates new if value is none
 
+    grid.set_display(config.live_glyph, config.dead_glyph, config.gradient.clone());
+
+    // Resize the terminal to fit a grid loaded from file; a no-op for a freshly
+    // created one, which already matches the terminal's current size.
+    grid.resize_terminal()?;
     grid.prepare_terminal()?;
 
     loop {
         if event::poll(Duration::from_millis(0))? {
             if let Event::Key(event) = event::read()? {
-                if !handle_key_event(event, &mut grid, &filename)? {
+                if !handle_key_event(event, &mut grid, &filename, &config.keys)? {
                     break;
                 }
             }
@@ -48,44 +47,77 @@ fn main() -> ah::Result<()> {
         grid.update_grid();
         grid.draw();
 
-        thread::sleep(Duration::from_millis(1000 / FPS));
+        thread::sleep(Duration::from_millis(1000 / config.fps));
     }
 
     Ok(())
 }
 
-fn handle_key_event(event: event::KeyEvent, grid: &mut Grid, filename: &str) -> ah::Result<bool> {
+/// Parses CLI args into (filename, whether a filename was actually given, an
+/// optional `--rule B<digits>/S<digits>` override).
+fn parse_args(mut args: impl Iterator<Item = String>) -> ah::Result<(String, bool, Option<Rule>)> {
+    let mut filename = None;
+    let mut rule = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--rule" {
+            let value = args
+                .next()
+                .ok_or_else(|| ah::anyhow!("--rule requires a value"))?;
+            rule = Some(Rule::parse(&value).map_err(|e| ah::anyhow!(e.to_string()))?);
+        } else if filename.is_none() {
+            filename = Some(arg);
+        }
+    }
+
+    match filename {
+        Some(filename) => Ok((filename, true, rule)),
+        None => Ok((String::from("grid.data"), false, rule)),
+    }
+}
+
+fn handle_key_event(
+    event: event::KeyEvent,
+    grid: &mut Grid,
+    filename: &str,
+    keys: &KeyBindings,
+) -> ah::Result<bool> {
     use KeyCode::{Char, Esc};
 
     let b = match event.code {
-        Char(r) if r.eq_ignore_ascii_case(&'r') => {
+        Char(c) if c.eq_ignore_ascii_case(&keys.restart) => {
             grid.restart();
             true
         }
 
-        Char(c_s) if event.modifiers.contains(KM::CONTROL) && c_s.eq_ignore_ascii_case(&'s') => {
+        Char(c) if event.modifiers.contains(KM::CONTROL) && c.eq_ignore_ascii_case(&keys.save_to_file) => {
             grid.save_to_file(filename)?;
             true
         }
 
-        Char(s) if s.eq_ignore_ascii_case(&'s') => {
+        Char(c) if c.eq_ignore_ascii_case(&keys.save_state) => {
             grid.save_state();
             true
         }
 
-        Char(l) if l.eq_ignore_ascii_case(&'l') => {
+        Char(c) if c.eq_ignore_ascii_case(&keys.load_state) => {
             grid.load_state();
             true
         }
 
-        Char(p) if p.eq_ignore_ascii_case(&'p') => {
+        Char(c) if c.eq_ignore_ascii_case(&keys.toggle_pause) => {
             grid.toggle_pause();
             true
         }
 
+        Char(c) if c.eq_ignore_ascii_case(&keys.cycle_neighborhood) => {
+            grid.cycle_neighborhood();
+            true
+        }
+
         // Break conditions
         Esc => false,
-        Char(q) if q.eq_ignore_ascii_case(&'q') => false,
+        Char(c) if c.eq_ignore_ascii_case(&keys.quit) => false,
         Char(c_c) if event.modifiers.contains(KM::CONTROL) && c_c.eq_ignore_ascii_case(&'c') => {
             false
         }
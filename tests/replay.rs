@@ -0,0 +1,30 @@
+use conways_terminal_of_life::conway::{Grid, Rule};
+
+const GENERATIONS: u64 = 4;
+
+fn glider_cells(rows: usize, cols: usize) -> Vec<Vec<bool>> {
+    let mut cells = vec![vec![false; cols]; rows];
+    for (r, c) in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)] {
+        cells[r][c] = true;
+    }
+    cells
+}
+
+/// Steps a glider under the default rule and compares each generation
+/// against a checked-in reference, catching regressions in the rule
+/// application or neighbor counting.
+#[test]
+fn glider_matches_recorded_generations() {
+    let mut grid = Grid::from_pattern(glider_cells(10, 10), Rule::default(), 42);
+
+    for gen in 0..=GENERATIONS {
+        let expected =
+            std::fs::read_to_string(format!("tests/fixtures/glider/gen_{gen}.txt")).unwrap();
+        assert_eq!(
+            grid.serialize_plain(),
+            expected,
+            "generation {gen} diverged from the recorded reference"
+        );
+        grid.step_n(1);
+    }
+}